@@ -0,0 +1,111 @@
+use soroban_sdk::{contractclient, contracttype, Address, Env, Symbol};
+
+use crate::{apply_bps, Bps, DataKey};
+
+// Fiyat oracle'ında sorgulanan varlık için enum
+// Reflector uyumlu beslemelerin varlık tanımıyla aynı yapıdadır
+#[derive(Clone)]
+#[contracttype]
+pub enum OracleAsset {
+    Stellar(Address), // Stellar üzerindeki token kontratı
+    Other(Symbol),    // Zincir dışı varlık sembolü
+}
+
+// Fiyat oracle'ından dönen fiyat verisi
+// Fiyat oracle'ın ondalık hassasiyetiyle ölçeklenmiş USD değeridir
+#[derive(Clone)]
+#[contracttype]
+pub struct OraclePriceData {
+    pub price: i128,            // Token biriminin USD fiyatı (oracle hassasiyetinde)
+    pub timestamp: u64,         // Fiyatın yayınlandığı zaman
+}
+
+// Fiyat oracle'ı ayarları
+// Eski veya son kabul edilen fiyattan çok sapan fiyatlar reddedilir
+#[derive(Clone)]
+#[contracttype]
+pub struct OracleConfig {
+    pub oracle: Address,        // Reflector uyumlu fiyat oracle'ı kontrat adresi
+    pub max_staleness: u64,     // Fiyatın kabul edilebilir en fazla yaşı (saniye)
+    pub max_deviation_bps: Bps, // Son kabul edilen fiyattan en fazla sapma (baz puan)
+}
+
+// Fiyat oracle'ı arayüzü
+// Reflector uyumlu beslemelerin kontratlar arası çağrı arayüzü; OracleClient bu arayüzden üretilir
+#[contractclient(name = "OracleClient")]
+pub trait OracleInterface {
+    fn lastprice(env: Env, asset: OracleAsset) -> Option<OraclePriceData>;
+    fn decimals(env: Env) -> u32;
+}
+
+// Yapılandırılmış oracle ayarlarını getir
+pub fn get_config(env: &Env) -> OracleConfig {
+    match env.storage().instance().get(&DataKey::OracleConfig) {
+        Some(config) => config,
+        None => panic!("price oracle not configured"),
+    }
+}
+
+// Oracle'dan bir tokenin USD fiyatını al ve tazelik ile sapma kontrollerini uygula
+// Fiyatı ve oracle'ın ondalık hassasiyetini döndürür
+pub fn get_price(env: &Env, token: &Address) -> (i128, u32) {
+    let config = get_config(env);
+    let client = OracleClient::new(env, &config.oracle);
+
+    let data = match client.lastprice(&OracleAsset::Stellar(token.clone())) {
+        Some(data) => data,
+        None => panic!("oracle price not available"),
+    };
+    if data.price <= 0 {
+        panic!("invalid oracle price");
+    }
+
+    // Fiyat en fazla yaşı aşmamalı
+    let now = env.ledger().timestamp();
+    if now > data.timestamp + config.max_staleness {
+        panic!("oracle price is stale");
+    }
+
+    // Güncel sayılan son kabul edilen fiyattan sapma sınırını aşmamalı
+    let last: Option<(i128, u64)> = env.storage().persistent().get(&DataKey::LastOraclePrice(token.clone()));
+    if let Some((last_price, last_time)) = last {
+        if now <= last_time + config.max_staleness
+            && (data.price - last_price).abs() > apply_bps(env, last_price, &config.max_deviation_bps)
+        {
+            panic!("oracle price deviates too much");
+        }
+    }
+
+    (data.price, client.decimals())
+}
+
+// Kabul edilen fiyatı sonraki sapma kontrolleri için kaydet
+pub fn record_price(env: &Env, token: &Address, price: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::LastOraclePrice(token.clone()), &(price, env.ledger().timestamp()));
+}
+
+// Testlerde kullanılan sahte fiyat oracle'ı
+// Yöneticinin belirlediği tek bir fiyatı tüm varlıklar için döndürür
+#[cfg(test)]
+#[soroban_sdk::contract]
+pub struct MockOracle;
+
+#[cfg(test)]
+#[soroban_sdk::contractimpl]
+impl MockOracle {
+    // Döndürülecek fiyatı ve yayın zamanını belirle
+    pub fn set_price(env: Env, price: i128, timestamp: u64) {
+        env.storage().instance().set(&soroban_sdk::symbol_short!("price"), &OraclePriceData { price, timestamp });
+    }
+
+    pub fn lastprice(env: Env, _asset: OracleAsset) -> Option<OraclePriceData> {
+        env.storage().instance().get(&soroban_sdk::symbol_short!("price"))
+    }
+
+    // Reflector beslemeleriyle aynı hassasiyet
+    pub fn decimals(_env: Env) -> u32 {
+        14
+    }
+}